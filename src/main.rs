@@ -9,12 +9,17 @@ use ratatui::{
     layout::{Alignment, Constraint, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{
+        canvas::{Canvas, Painter, Shape},
+        Block, Borders, Paragraph,
+    },
     Frame, Terminal,
 };
 use std::{
     collections::VecDeque,
+    fs,
     io::{self, stdout},
+    path::PathBuf,
     time::{Duration, Instant},
 };
 
@@ -29,15 +34,31 @@ const BASE_TICK_MS: u64 = 200;
 const MIN_TICK_MS: u64 = 50;
 const SPEED_INCREASE_PER_FOOD: u64 = 5;
 
+// Each food spawns with this much time left; it drains by FOOD_TIME_DECAY
+// every FOOD_TIMER_INTERVAL_MS of real time, independent of the tick rate.
+const FOOD_TIME_INITIAL: u32 = 100;
+const FOOD_TIME_DECAY: u32 = 10;
+const FOOD_TIMER_INTERVAL_MS: u64 = 800;
+
+// Eating this many foods advances to the next level, which regenerates the
+// wall layout (WALLS_PER_LEVEL more walls each time) and resets the snake.
+const FOODS_PER_LEVEL: u32 = 5;
+const WALLS_PER_LEVEL: usize = 3;
+
+// High-score table
+const HIGH_SCORE_CAPACITY: usize = 10;
+const MAX_NAME_LEN: usize = 16;
+
 // Symbols
 const SNAKE_BODY: &str = "●";
 const FOOD_SYMBOL: &str = "●";
+const WALL_SYMBOL: &str = "█";
 
 // ============================================================================
 // Types
 // ============================================================================
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 struct Position {
     x: i16,
     y: i16,
@@ -62,20 +83,91 @@ impl Direction {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum WallMode {
+    /// Running into the edge of the grid ends the game.
+    Solid,
+    /// Crossing an edge re-enters the grid from the opposite side.
+    Wrap,
+}
+
+impl WallMode {
+    fn label(&self) -> &'static str {
+        match self {
+            WallMode::Solid => "Solid",
+            WallMode::Wrap => "Wrap-around",
+        }
+    }
+
+    fn cycle(&self) -> WallMode {
+        match self {
+            WallMode::Solid => WallMode::Wrap,
+            WallMode::Wrap => WallMode::Solid,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RenderMode {
+    /// Per-cell `Paragraph` of `Span`s, one glyph pair per grid cell.
+    Grid,
+    /// `widgets::canvas::Canvas` painting, scales to fill the terminal.
+    Canvas,
+}
+
+impl RenderMode {
+    fn cycle(&self) -> RenderMode {
+        match self {
+            RenderMode::Grid => RenderMode::Canvas,
+            RenderMode::Canvas => RenderMode::Grid,
+        }
+    }
+}
+
 enum GameState {
+    /// Pre-game screen where the player picks a wall mode before starting.
+    Menu,
     Playing,
+    Paused,
     GameOver,
 }
 
+/// Emitted by `tick` when the snake eats food, so the loop can react to the
+/// event (e.g. future sound/flash hooks) without re-deriving it from state.
+struct GrowthEvent;
+
+/// Emitted by `tick` when a collision ends the run.
+struct GameOverEvent;
+
+#[derive(Default)]
+struct TickEvents {
+    growth: Option<GrowthEvent>,
+    game_over: Option<GameOverEvent>,
+}
+
+struct HighScore {
+    name: String,
+    score: u32,
+}
+
 struct Game {
     snake: VecDeque<Position>,
     direction: Direction,
     next_direction: Direction,
     food: Position,
+    food_time_remaining: u32,
     score: u32,
     state: GameState,
     grid_width: u16,
     grid_height: u16,
+    walls: Vec<Position>,
+    level: u32,
+    level_progress: u32,
+    foods_eaten: u32,
+    wall_mode: WallMode,
+    render_mode: RenderMode,
+    high_scores: Vec<HighScore>,
+    entering_name: Option<String>,
 }
 
 // ============================================================================
@@ -83,11 +175,37 @@ struct Game {
 // ============================================================================
 
 impl Game {
-    fn new(grid_width: u16, grid_height: u16) -> Self {
+    fn new(grid_width: u16, grid_height: u16, wall_mode: WallMode) -> Self {
+        let mut game = Game {
+            snake: Self::initial_snake(grid_width, grid_height),
+            direction: Direction::Right,
+            next_direction: Direction::Right,
+            food: Position { x: 0, y: 0 },
+            food_time_remaining: FOOD_TIME_INITIAL,
+            score: 0,
+            state: GameState::Menu,
+            grid_width,
+            grid_height,
+            walls: Vec::new(),
+            level: 1,
+            level_progress: 0,
+            foods_eaten: 0,
+            wall_mode,
+            render_mode: RenderMode::Grid,
+            high_scores: load_high_scores(),
+            entering_name: None,
+        };
+
+        game.walls = Self::generate_walls(game.level, grid_width, grid_height);
+        game.spawn_food();
+        game
+    }
+
+    /// Builds the horizontal, right-facing starting snake centered on the grid.
+    fn initial_snake(grid_width: u16, grid_height: u16) -> VecDeque<Position> {
         let center_x = grid_width as i16 / 2;
         let center_y = grid_height as i16 / 2;
 
-        // Create initial snake (horizontal, facing right)
         let mut snake = VecDeque::new();
         for i in 0..INITIAL_SNAKE_LENGTH {
             snake.push_back(Position {
@@ -95,20 +213,46 @@ impl Game {
                 y: center_y,
             });
         }
+        snake
+    }
 
-        let mut game = Game {
-            snake,
-            direction: Direction::Right,
-            next_direction: Direction::Right,
-            food: Position { x: 0, y: 0 },
-            score: 0,
-            state: GameState::Playing,
-            grid_width,
-            grid_height,
-        };
+    /// Scatters `level * WALLS_PER_LEVEL` obstacle cells around the grid,
+    /// keeping the cells of the center row within `INITIAL_SNAKE_LENGTH` of
+    /// the center clear so a reset never drops the snake onto a wall.
+    fn generate_walls(level: u32, grid_width: u16, grid_height: u16) -> Vec<Position> {
+        let center_x = grid_width as i16 / 2;
+        let center_y = grid_height as i16 / 2;
+        let clearance = INITIAL_SNAKE_LENGTH as i16;
 
-        game.spawn_food();
-        game
+        let mut rng = rand::thread_rng();
+        let wall_count = level as usize * WALLS_PER_LEVEL;
+        let mut walls = Vec::new();
+
+        while walls.len() < wall_count {
+            let pos = Position {
+                x: rng.gen_range(0..grid_width as i16),
+                y: rng.gen_range(0..grid_height as i16),
+            };
+
+            let in_spawn_row = pos.y == center_y && (pos.x - center_x).abs() <= clearance;
+            if !in_spawn_row && !walls.contains(&pos) {
+                walls.push(pos);
+            }
+        }
+
+        walls
+    }
+
+    /// Advances to the next level: regenerates a denser wall layout, resets
+    /// the snake to center, and respawns food, all while keeping the score.
+    fn level_up(&mut self) {
+        self.level += 1;
+        self.level_progress = 0;
+        self.walls = Self::generate_walls(self.level, self.grid_width, self.grid_height);
+        self.snake = Self::initial_snake(self.grid_width, self.grid_height);
+        self.direction = Direction::Right;
+        self.next_direction = Direction::Right;
+        self.spawn_food();
     }
 
     fn spawn_food(&mut self) {
@@ -119,45 +263,71 @@ impl Game {
                 y: rng.gen_range(0..self.grid_height as i16),
             };
 
-            // Ensure food doesn't spawn on snake
-            if !self.snake.contains(&pos) {
+            // Ensure food doesn't spawn on the snake or a wall
+            if !self.snake.contains(&pos) && !self.walls.contains(&pos) {
                 self.food = pos;
+                self.food_time_remaining = FOOD_TIME_INITIAL;
                 break;
             }
         }
     }
 
-    fn tick(&mut self) {
+    /// Drains the current food's countdown by one step. Called roughly every
+    /// `FOOD_TIMER_INTERVAL_MS` of real time. If the timer runs out, the food
+    /// despawns and a fresh one is spawned elsewhere without growing the snake.
+    fn decay_food_timer(&mut self) {
         if !matches!(self.state, GameState::Playing) {
             return;
         }
 
+        self.food_time_remaining = self.food_time_remaining.saturating_sub(FOOD_TIME_DECAY);
+        if self.food_time_remaining == 0 {
+            self.spawn_food();
+        }
+    }
+
+    fn tick(&mut self) -> TickEvents {
+        if !matches!(self.state, GameState::Playing) {
+            return TickEvents::default();
+        }
+
         // Apply the queued direction change
         self.direction = self.next_direction;
 
         // Calculate new head position
         let head = self.snake.front().unwrap();
-        let new_head = match self.direction {
+        let mut new_head = match self.direction {
             Direction::Up => Position { x: head.x, y: head.y - 1 },
             Direction::Down => Position { x: head.x, y: head.y + 1 },
             Direction::Left => Position { x: head.x - 1, y: head.y },
             Direction::Right => Position { x: head.x + 1, y: head.y },
         };
 
-        // Check wall collision
-        if new_head.x < 0
-            || new_head.x >= self.grid_width as i16
-            || new_head.y < 0
-            || new_head.y >= self.grid_height as i16
-        {
-            self.state = GameState::GameOver;
-            return;
+        // Check grid boundary, handling it according to the chosen wall mode
+        match self.wall_mode {
+            WallMode::Solid => {
+                if new_head.x < 0
+                    || new_head.x >= self.grid_width as i16
+                    || new_head.y < 0
+                    || new_head.y >= self.grid_height as i16
+                {
+                    return self.game_over();
+                }
+            }
+            WallMode::Wrap => {
+                new_head.x = new_head.x.rem_euclid(self.grid_width as i16);
+                new_head.y = new_head.y.rem_euclid(self.grid_height as i16);
+            }
         }
 
         // Check self collision
         if self.snake.contains(&new_head) {
-            self.state = GameState::GameOver;
-            return;
+            return self.game_over();
+        }
+
+        // Check wall/obstacle collision
+        if self.walls.contains(&new_head) {
+            return self.game_over();
         }
 
         // Move snake
@@ -165,12 +335,56 @@ impl Game {
 
         // Check food collision
         if new_head == self.food {
-            self.score += 1;
-            self.spawn_food();
+            self.score += self.food_time_remaining;
+            self.foods_eaten += 1;
+            self.level_progress += 1;
+            if self.level_progress >= FOODS_PER_LEVEL {
+                self.level_up();
+            } else {
+                self.spawn_food();
+            }
             // Don't remove tail - snake grows
+            TickEvents {
+                growth: Some(GrowthEvent),
+                ..TickEvents::default()
+            }
         } else {
             self.snake.pop_back();
+            TickEvents::default()
+        }
+    }
+
+    /// Ends the run: flips to `GameOver` and, if the score earned a spot on
+    /// the high-score table, opens the name-entry prompt.
+    fn game_over(&mut self) -> TickEvents {
+        self.state = GameState::GameOver;
+        if self.qualifies_for_high_score() {
+            self.entering_name = Some(String::new());
         }
+        TickEvents {
+            game_over: Some(GameOverEvent),
+            ..TickEvents::default()
+        }
+    }
+
+    fn qualifies_for_high_score(&self) -> bool {
+        self.score > 0
+            && (self.high_scores.len() < HIGH_SCORE_CAPACITY
+                || self.high_scores.last().is_some_and(|lowest| self.score > lowest.score))
+    }
+
+    /// Commits the name currently being entered into the high-score table,
+    /// re-sorts it, trims it to `HIGH_SCORE_CAPACITY`, and persists it to disk.
+    fn commit_high_score_name(&mut self) {
+        let Some(name) = self.entering_name.take() else {
+            return;
+        };
+        let name = if name.trim().is_empty() { "Anonymous".to_string() } else { name.trim().to_string() };
+
+        self.high_scores.push(HighScore { name, score: self.score });
+        self.high_scores.sort_by_key(|entry| std::cmp::Reverse(entry.score));
+        self.high_scores.truncate(HIGH_SCORE_CAPACITY);
+        save_high_scores(&self.high_scores);
     }
 
     fn change_direction(&mut self, new_direction: Direction) {
@@ -181,12 +395,66 @@ impl Game {
     }
 
     fn tick_duration(&self) -> Duration {
-        let speed_reduction = self.score as u64 * SPEED_INCREASE_PER_FOOD;
+        let speed_reduction = self.foods_eaten as u64 * SPEED_INCREASE_PER_FOOD;
         let tick_ms = BASE_TICK_MS.saturating_sub(speed_reduction).max(MIN_TICK_MS);
         Duration::from_millis(tick_ms)
     }
 }
 
+// ============================================================================
+// Persistence
+// ============================================================================
+
+/// Where the high-score table lives: `$XDG_DATA_HOME` if set, else
+/// `~/.local/share`, falling back to the current directory if neither is.
+fn high_score_path() -> PathBuf {
+    let base = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+        .unwrap_or_else(|| PathBuf::from("."));
+
+    base.join("terminal-snake").join("highscores.txt")
+}
+
+/// Parses one `name:score` line, as written by `save_high_scores`.
+fn parse_high_score_line(line: &str) -> Option<HighScore> {
+    let (name, score) = line.rsplit_once(':')?;
+    Some(HighScore {
+        name: name.to_string(),
+        score: score.trim().parse().ok()?,
+    })
+}
+
+/// Loads the high-score table from disk. Missing or unreadable files (e.g.
+/// first run) simply mean an empty table rather than an error.
+fn load_high_scores() -> Vec<HighScore> {
+    let Ok(contents) = fs::read_to_string(high_score_path()) else {
+        return Vec::new();
+    };
+
+    let mut scores: Vec<HighScore> = contents.lines().filter_map(parse_high_score_line).collect();
+
+    scores.sort_by_key(|entry| std::cmp::Reverse(entry.score));
+    scores.truncate(HIGH_SCORE_CAPACITY);
+    scores
+}
+
+/// Persists the high-score table as `name:score` lines. Best-effort: a
+/// failure to save (e.g. read-only home) shouldn't crash the game.
+fn save_high_scores(scores: &[HighScore]) {
+    let path = high_score_path();
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    let contents = scores
+        .iter()
+        .map(|entry| format!("{}:{}", entry.name, entry.score))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let _ = fs::write(path, contents);
+}
+
 // ============================================================================
 // Rendering
 // ============================================================================
@@ -195,12 +463,24 @@ fn render(frame: &mut Frame, game: &Game) {
     let area = frame.size();
 
     match game.state {
+        GameState::Menu => render_wall_mode_menu(frame, game.wall_mode, &game.high_scores),
         GameState::Playing => render_game(frame, game, area),
+        GameState::Paused => {
+            render_game(frame, game, area);
+            render_paused_overlay(frame, area);
+        }
         GameState::GameOver => render_game_over(frame, game, area),
     }
 }
 
 fn render_game(frame: &mut Frame, game: &Game, area: Rect) {
+    match game.render_mode {
+        RenderMode::Grid => render_game_grid(frame, game, area),
+        RenderMode::Canvas => render_game_canvas(frame, game, area),
+    }
+}
+
+fn render_game_grid(frame: &mut Frame, game: &Game, area: Rect) {
     // Calculate the size needed for the game grid
     // Each cell is 2 characters wide for better aspect ratio
     let grid_width = game.grid_width * 2 + 2; // +2 for borders
@@ -212,7 +492,10 @@ fn render_game(frame: &mut Frame, game: &Game, area: Rect) {
     // Create the game board
     let block = Block::default()
         .borders(Borders::ALL)
-        .title(format!(" Snake - Score: {} ", game.score))
+        .title(format!(
+            " Snake - Score: {} | Level: {} | Food: {} ",
+            game.score, game.level, game.food_time_remaining
+        ))
         .title_alignment(Alignment::Center);
 
     let inner = block.inner(game_area);
@@ -235,6 +518,9 @@ fn render_game(frame: &mut Frame, game: &Game, area: Rect) {
             } else if pos == game.food {
                 // Food
                 (FOOD_SYMBOL, Style::default().fg(Color::Red))
+            } else if game.walls.contains(&pos) {
+                // Obstacle wall
+                (WALL_SYMBOL, Style::default().fg(Color::Gray))
             } else {
                 // Empty cell
                 ("  ", Style::default())
@@ -264,7 +550,114 @@ fn render_game(frame: &mut Frame, game: &Game, area: Rect) {
     };
 
     if controls_area.y < area.height {
-        let controls = Paragraph::new("WASD: Move | ESC: Quit")
+        let controls = Paragraph::new("WASD: Move | P: Pause | C: Toggle Renderer | ESC: Quit")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(controls, controls_area);
+    }
+}
+
+/// `canvas::Rectangle` only paints an outline, which leaves 1x1 cells looking
+/// like hollow boxes. This paints every pixel in the cell's bounds instead,
+/// so it reads as a solid block regardless of the canvas's resolution.
+struct FilledCell {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    color: Color,
+}
+
+impl Shape for FilledCell {
+    fn draw(&self, painter: &mut Painter) {
+        let (Some((x_min, y_min)), Some((x_max, y_max))) = (
+            painter.get_point(self.x, self.y),
+            painter.get_point(self.x + self.width, self.y + self.height),
+        ) else {
+            return;
+        };
+
+        for px in x_min.min(x_max)..=x_min.max(x_max) {
+            for py in y_min.min(y_max)..=y_min.max(y_max) {
+                painter.paint(px, py, self.color);
+            }
+        }
+    }
+}
+
+/// Canvas-backed alternative to `render_game_grid`: draws snake segments,
+/// food, and walls as filled cells on a `Canvas`, so the board scales to
+/// fill the terminal instead of being locked to the 2-char-per-cell grid.
+fn render_game_canvas(frame: &mut Frame, game: &Game, area: Rect) {
+    let controls_height = 1;
+    let game_area = Rect {
+        x: area.x,
+        y: area.y,
+        width: area.width,
+        height: area.height.saturating_sub(controls_height),
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            " Snake - Score: {} | Level: {} | Food: {} ",
+            game.score, game.level, game.food_time_remaining
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner = block.inner(game_area);
+    frame.render_widget(block, game_area);
+
+    let grid_width = game.grid_width as f64;
+    let grid_height = game.grid_height as f64;
+
+    // Canvas y grows upward, but grid y grows downward, so flip when painting.
+    let flip_y = |y: i16| grid_height - y as f64 - 1.0;
+
+    let canvas = Canvas::default()
+        .x_bounds([0.0, grid_width])
+        .y_bounds([0.0, grid_height])
+        .paint(move |ctx| {
+            for wall in &game.walls {
+                ctx.draw(&FilledCell {
+                    x: wall.x as f64,
+                    y: flip_y(wall.y),
+                    width: 1.0,
+                    height: 1.0,
+                    color: Color::Gray,
+                });
+            }
+
+            for (i, segment) in game.snake.iter().enumerate() {
+                ctx.draw(&FilledCell {
+                    x: segment.x as f64,
+                    y: flip_y(segment.y),
+                    width: 1.0,
+                    height: 1.0,
+                    color: if i == 0 { Color::Green } else { Color::LightGreen },
+                });
+            }
+
+            ctx.draw(&FilledCell {
+                x: game.food.x as f64,
+                y: flip_y(game.food.y),
+                width: 1.0,
+                height: 1.0,
+                color: Color::Red,
+            });
+        });
+
+    frame.render_widget(canvas, inner);
+
+    let controls_area = Rect {
+        x: area.x,
+        y: game_area.y + game_area.height,
+        width: area.width,
+        height: controls_height,
+    };
+
+    if controls_area.y < area.height {
+        let controls = Paragraph::new("WASD: Move | P: Pause | C: Toggle Renderer | ESC: Quit")
             .alignment(Alignment::Center)
             .style(Style::default().fg(Color::DarkGray));
         frame.render_widget(controls, controls_area);
@@ -272,7 +665,7 @@ fn render_game(frame: &mut Frame, game: &Game, area: Rect) {
 }
 
 fn render_game_over(frame: &mut Frame, game: &Game, area: Rect) {
-    let text = vec![
+    let mut text = vec![
         Line::from(""),
         Line::from(Span::styled(
             "GAME OVER",
@@ -281,22 +674,96 @@ fn render_game_over(frame: &mut Frame, game: &Game, area: Rect) {
         Line::from(""),
         Line::from(format!("Final Score: {}", game.score)),
         Line::from(""),
+    ];
+
+    if let Some(name) = &game.entering_name {
+        text.push(Line::from(Span::styled(
+            "New high score! Enter your name:",
+            Style::default().fg(Color::Yellow),
+        )));
+        text.push(Line::from(format!("{name}_")));
+        text.push(Line::from(""));
+        text.push(Line::from(Span::styled(
+            "Enter: Save | ESC: Quit",
+            Style::default().fg(Color::DarkGray),
+        )));
+    } else {
+        text.push(Line::from(Span::styled(
+            "Enter: Restart | ESC: Quit",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let popup_height = (text.len() as u16 + 2).min(area.height);
+    let paragraph = Paragraph::new(text)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Game Over ")
+                .title_alignment(Alignment::Center),
+        );
+
+    let popup_area = centered_rect(36, popup_height, area);
+    frame.render_widget(paragraph, popup_area);
+}
+
+fn render_paused_overlay(frame: &mut Frame, area: Rect) {
+    let text = vec![
+        Line::from(""),
+        Line::from(Span::styled("PAUSED", Style::default().fg(Color::Yellow))),
+        Line::from(""),
         Line::from(Span::styled(
-            "Press ESC to quit",
+            "P: Resume | ESC: Quit",
             Style::default().fg(Color::DarkGray),
         )),
     ];
 
+    let paragraph = Paragraph::new(text)
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+
+    let popup_area = centered_rect(24, 6, area);
+    frame.render_widget(paragraph, popup_area);
+}
+
+fn render_wall_mode_menu(frame: &mut Frame, wall_mode: WallMode, high_scores: &[HighScore]) {
+    let area = frame.size();
+
+    let mut text = vec![
+        Line::from(""),
+        Line::from(Span::styled("SNAKE", Style::default().fg(Color::Green))),
+        Line::from(""),
+        Line::from(format!("Wall mode: {}", wall_mode.label())),
+        Line::from(""),
+        Line::from(Span::styled("High Scores", Style::default().fg(Color::Yellow))),
+    ];
+
+    if high_scores.is_empty() {
+        text.push(Line::from("  (none yet)"));
+    } else {
+        for (i, entry) in high_scores.iter().enumerate() {
+            text.push(Line::from(format!("  {:>2}. {:<12} {}", i + 1, entry.name, entry.score)));
+        }
+    }
+
+    text.push(Line::from(""));
+    text.push(Line::from(Span::styled(
+        "M: Change mode | Enter: Start | ESC: Quit",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let popup_height = (text.len() as u16 + 2).min(area.height);
     let paragraph = Paragraph::new(text)
         .alignment(Alignment::Center)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Game Over ")
+                .title(" Menu ")
                 .title_alignment(Alignment::Center),
         );
 
-    let popup_area = centered_rect(30, 10, area);
+    let popup_area = centered_rect(40, popup_height, area);
     frame.render_widget(paragraph, popup_area);
 }
 
@@ -329,49 +796,124 @@ fn main() -> io::Result<()> {
     let backend = CrosstermBackend::new(stdout());
     let mut terminal = Terminal::new(backend)?;
 
-    // Create game
-    let mut game = Game::new(GRID_WIDTH, GRID_HEIGHT);
+    // Game starts on the wall-mode menu; Game::new defaults to GameState::Menu
+    let mut game = Game::new(GRID_WIDTH, GRID_HEIGHT, WallMode::Solid);
     let mut last_tick = Instant::now();
+    let mut last_food_tick = Instant::now();
 
     // Main loop
     loop {
         // Render
         terminal.draw(|frame| render(frame, &game))?;
 
-        // Calculate time until next tick
+        // Calculate time until next tick, capped by whichever of the snake's
+        // tick clock or the food's countdown clock needs to fire first
         let tick_duration = game.tick_duration();
-        let timeout = tick_duration
+        let tick_timeout = tick_duration
             .checked_sub(last_tick.elapsed())
             .unwrap_or(Duration::ZERO);
+        let food_timeout = Duration::from_millis(FOOD_TIMER_INTERVAL_MS)
+            .checked_sub(last_food_tick.elapsed())
+            .unwrap_or(Duration::ZERO);
+        // Outside of Playing neither clock advances, so the computed timeout
+        // would collapse to zero and busy-spin the loop; floor it so the
+        // menu/pause/game-over screens block on input instead.
+        let timeout = if matches!(game.state, GameState::Playing) {
+            tick_timeout.min(food_timeout)
+        } else {
+            tick_timeout.min(food_timeout).max(Duration::from_millis(100))
+        };
 
-        // Handle input
+        // Handle input; which keys apply depends on the current state
         if event::poll(timeout)? {
             if let Event::Key(key) = event::read()? {
                 if key.kind == KeyEventKind::Press {
-                    match key.code {
-                        KeyCode::Esc => break,
-                        KeyCode::Char('w') | KeyCode::Char('W') => {
-                            game.change_direction(Direction::Up);
+                    if matches!(game.state, GameState::GameOver) && game.entering_name.is_some() {
+                        // A high score was just earned: route keys into the name prompt
+                        match key.code {
+                            KeyCode::Esc => break,
+                            KeyCode::Enter => game.commit_high_score_name(),
+                            KeyCode::Backspace => {
+                                if let Some(name) = &mut game.entering_name {
+                                    name.pop();
+                                }
+                            }
+                            KeyCode::Char(c) if !c.is_control() && c != ':' => {
+                                if let Some(name) = &mut game.entering_name {
+                                    if name.chars().count() < MAX_NAME_LEN {
+                                        name.push(c);
+                                    }
+                                }
+                            }
+                            _ => {}
                         }
-                        KeyCode::Char('s') | KeyCode::Char('S') => {
-                            game.change_direction(Direction::Down);
+                    } else {
+                        match (&game.state, key.code) {
+                            (_, KeyCode::Esc) => break,
+                            (GameState::Menu, KeyCode::Char('m') | KeyCode::Char('M')) => {
+                                game.wall_mode = game.wall_mode.cycle();
+                            }
+                            (GameState::Menu, KeyCode::Enter) => {
+                                game.state = GameState::Playing;
+                                last_tick = Instant::now();
+                                last_food_tick = Instant::now();
+                            }
+                            (GameState::Playing, KeyCode::Char('w') | KeyCode::Char('W')) => {
+                                game.change_direction(Direction::Up);
+                            }
+                            (GameState::Playing, KeyCode::Char('s') | KeyCode::Char('S')) => {
+                                game.change_direction(Direction::Down);
+                            }
+                            (GameState::Playing, KeyCode::Char('a') | KeyCode::Char('A')) => {
+                                game.change_direction(Direction::Left);
+                            }
+                            (GameState::Playing, KeyCode::Char('d') | KeyCode::Char('D')) => {
+                                game.change_direction(Direction::Right);
+                            }
+                            (GameState::Playing, KeyCode::Char('c') | KeyCode::Char('C')) => {
+                                game.render_mode = game.render_mode.cycle();
+                            }
+                            (GameState::Playing, KeyCode::Char('p') | KeyCode::Char('P')) => {
+                                game.state = GameState::Paused;
+                            }
+                            (GameState::Paused, KeyCode::Char('p') | KeyCode::Char('P')) => {
+                                game.state = GameState::Playing;
+                                last_tick = Instant::now();
+                                last_food_tick = Instant::now();
+                            }
+                            (GameState::GameOver, KeyCode::Enter) => {
+                                let wall_mode = game.wall_mode;
+                                game = Game::new(GRID_WIDTH, GRID_HEIGHT, wall_mode);
+                                game.state = GameState::Playing;
+                                last_tick = Instant::now();
+                                last_food_tick = Instant::now();
+                            }
+                            _ => {}
                         }
-                        KeyCode::Char('a') | KeyCode::Char('A') => {
-                            game.change_direction(Direction::Left);
-                        }
-                        KeyCode::Char('d') | KeyCode::Char('D') => {
-                            game.change_direction(Direction::Right);
-                        }
-                        _ => {}
                     }
                 }
             }
         }
 
-        // Update game state
-        if last_tick.elapsed() >= tick_duration {
-            game.tick();
-            last_tick = Instant::now();
+        // Advance the simulation; ticking and the food countdown only run
+        // while actually playing, so Menu/Paused/GameOver freeze the board
+        if matches!(game.state, GameState::Playing) {
+            if last_tick.elapsed() >= tick_duration {
+                match game.tick() {
+                    TickEvents { game_over: Some(_), .. } => {
+                        // Run ended; `state` is already `GameOver`.
+                    }
+                    TickEvents { growth: Some(_), .. } => {
+                        // Food eaten; score/level bookkeeping already ran in `tick`.
+                    }
+                    TickEvents { .. } => {}
+                }
+                last_tick = Instant::now();
+            }
+            if last_food_tick.elapsed() >= Duration::from_millis(FOOD_TIMER_INTERVAL_MS) {
+                game.decay_food_timer();
+                last_food_tick = Instant::now();
+            }
         }
     }
 
@@ -381,3 +923,159 @@ fn main() -> io::Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn food_timer_decays_by_fixed_amount() {
+        let mut game = Game::new(10, 10, WallMode::Solid);
+        game.state = GameState::Playing;
+        let start = game.food_time_remaining;
+
+        game.decay_food_timer();
+
+        assert_eq!(game.food_time_remaining, start - FOOD_TIME_DECAY);
+    }
+
+    #[test]
+    fn food_timer_expiry_respawns_without_growing_snake() {
+        let mut game = Game::new(10, 10, WallMode::Solid);
+        game.state = GameState::Playing;
+        let snake_len_before = game.snake.len();
+        game.food_time_remaining = FOOD_TIME_DECAY;
+
+        game.decay_food_timer();
+
+        assert_eq!(game.food_time_remaining, FOOD_TIME_INITIAL);
+        assert_eq!(game.snake.len(), snake_len_before);
+    }
+
+    #[test]
+    fn food_timer_only_decays_while_playing() {
+        let mut game = Game::new(10, 10, WallMode::Solid);
+        let start = game.food_time_remaining;
+
+        game.decay_food_timer();
+
+        assert_eq!(game.food_time_remaining, start);
+    }
+
+    #[test]
+    fn wrap_mode_wraps_head_around_grid_edges() {
+        let mut game = Game::new(5, 5, WallMode::Wrap);
+        game.state = GameState::Playing;
+        game.snake = VecDeque::from([Position { x: 0, y: 2 }]);
+        game.direction = Direction::Left;
+        game.next_direction = Direction::Left;
+        game.walls.clear();
+        game.food = Position { x: 4, y: 4 };
+
+        let events = game.tick();
+
+        assert!(events.game_over.is_none());
+        assert_eq!(game.snake.front().copied(), Some(Position { x: 4, y: 2 }));
+    }
+
+    #[test]
+    fn solid_mode_ends_the_game_at_grid_edges() {
+        let mut game = Game::new(5, 5, WallMode::Solid);
+        game.state = GameState::Playing;
+        game.snake = VecDeque::from([Position { x: 0, y: 2 }]);
+        game.direction = Direction::Left;
+        game.next_direction = Direction::Left;
+        game.walls.clear();
+
+        let events = game.tick();
+
+        assert!(events.game_over.is_some());
+        assert!(matches!(game.state, GameState::GameOver));
+    }
+
+    #[test]
+    fn generate_walls_keeps_spawn_row_clear() {
+        let grid_width = 20;
+        let grid_height = 20;
+        let center_x = grid_width as i16 / 2;
+        let center_y = grid_height as i16 / 2;
+        let clearance = INITIAL_SNAKE_LENGTH as i16;
+
+        let walls = Game::generate_walls(5, grid_width, grid_height);
+
+        assert_eq!(walls.len(), 5 * WALLS_PER_LEVEL);
+        for wall in &walls {
+            let in_spawn_row = wall.y == center_y && (wall.x - center_x).abs() <= clearance;
+            assert!(!in_spawn_row, "wall spawned on the snake's starting row: {wall:?}");
+        }
+    }
+
+    #[test]
+    fn high_score_line_format_round_trips() {
+        let original = HighScore { name: "ada".to_string(), score: 245 };
+        let line = format!("{}:{}", original.name, original.score);
+
+        let parsed = parse_high_score_line(&line).expect("well-formed line should parse");
+
+        assert_eq!(parsed.name, original.name);
+        assert_eq!(parsed.score, original.score);
+    }
+
+    #[test]
+    fn parse_high_score_line_rejects_malformed_input() {
+        assert!(parse_high_score_line("no-colon-here").is_none());
+        assert!(parse_high_score_line("name:not-a-number").is_none());
+    }
+
+    #[test]
+    fn filled_cell_paints_more_than_just_an_outline() {
+        use ratatui::{buffer::Buffer, widgets::Widget};
+
+        let area = Rect::new(0, 0, 10, 10);
+        let mut buf = Buffer::empty(area);
+        let canvas = Canvas::default().x_bounds([0.0, 10.0]).y_bounds([0.0, 10.0]).paint(|ctx| {
+            ctx.draw(&FilledCell {
+                x: 2.0,
+                y: 2.0,
+                width: 4.0,
+                height: 4.0,
+                color: Color::Green,
+            });
+        });
+        canvas.render(area, &mut buf);
+
+        let painted_cells = buf.content().iter().filter(|cell| cell.fg == Color::Green).count();
+        // An outline would only paint the perimeter; a filled cell paints the interior too.
+        assert!(painted_cells > 4, "expected more than a thin outline to be painted, got {painted_cells}");
+        // Cell (3, 5) lies strictly inside the painted bounds, so only a fill reaches it.
+        let interior = &buf.content()[buf.index_of(3, 5)];
+        assert_eq!(interior.fg, Color::Green, "interior of the filled cell was not painted");
+    }
+
+    #[test]
+    fn tick_is_a_no_op_outside_playing() {
+        let mut game = Game::new(10, 10, WallMode::Solid);
+        for state in [GameState::Menu, GameState::Paused, GameState::GameOver] {
+            game.state = state;
+            let snake_before = game.snake.clone();
+
+            let events = game.tick();
+
+            assert!(events.growth.is_none());
+            assert!(events.game_over.is_none());
+            assert_eq!(game.snake, snake_before);
+        }
+    }
+
+    #[test]
+    fn game_over_opens_name_entry_only_for_a_qualifying_score() {
+        let mut game = Game::new(10, 10, WallMode::Solid);
+        game.state = GameState::Playing;
+        game.score = 0;
+
+        game.game_over();
+
+        assert!(matches!(game.state, GameState::GameOver));
+        assert!(game.entering_name.is_none());
+    }
+}